@@ -1,4 +1,5 @@
 use std::collections::hash_map::DefaultHasher;
+use std::f64::consts::PI;
 use std::hash::{Hash, Hasher};
 use std::ops::RangeBounds;
 use std::thread;
@@ -7,14 +8,17 @@ use instant::Instant;
 
 use crate::Rng;
 
+/// Mixes the current time and thread id into a seed for a fresh generator.
+fn random_seed() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    Instant::now().hash(&mut hasher);
+    thread::current().id().hash(&mut hasher);
+    let hash = hasher.finish();
+    (hash << 1) | 1
+}
+
 std::thread_local! {
-    static RNG: Rng = Rng::with_seed({
-        let mut hasher = DefaultHasher::new();
-        Instant::now().hash(&mut hasher);
-        thread::current().id().hash(&mut hasher);
-        let hash = hasher.finish();
-        (hash << 1) | 1
-    });
+    static RNG: Rng = Rng::with_seed(random_seed());
 }
 
 impl Rng {
@@ -35,12 +39,61 @@ impl Default for Rng {
     }
 }
 
+impl Rng {
+    /// Generates a random `f64` from a normal distribution with the given `mean` and
+    /// `std_dev`.
+    ///
+    /// Uses the Box–Muller transform and returns only the cosine variate of each pair. The
+    /// sine variate is not computed or cached, so every call draws two fresh uniforms.
+    pub fn f64_normal(&self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = loop {
+            let u1 = self.f64();
+            if u1 != 0.0 {
+                break u1;
+            }
+        };
+        let u2 = self.f64();
+        let r = (-2.0 * u1.ln()).sqrt();
+        let z0 = r * (2.0 * PI * u2).cos();
+        mean + std_dev * z0
+    }
+
+    /// Generates a random `f64` from an exponential distribution with rate `lambda`.
+    pub fn f64_exponential(&self, lambda: f64) -> f64 {
+        let u = loop {
+            let u = self.f64();
+            if u != 0.0 {
+                break u;
+            }
+        };
+        -u.ln() / lambda
+    }
+}
+
 /// Initializes the thread-local generator with the given seed.
 #[inline]
 pub fn seed(seed: u64) {
     RNG.with(|rng| rng.seed(seed))
 }
 
+/// Returns a new generator, seeded from the thread-local one.
+///
+/// This is not a shared handle: the returned `Rng` is seeded from a single draw off the
+/// thread-local generator (the same construction as [`Rng::new`]), not cloned from its state, so
+/// its stream doesn't replay or correlate with the thread-local one. Grabbing one up front and
+/// reusing it still avoids the repeated thread-local lookups that come with calling the free
+/// functions in a hot loop.
+#[inline]
+pub fn rng() -> Rng {
+    Rng::new()
+}
+
+/// Reseeds the thread-local generator from fresh entropy.
+#[inline]
+pub fn reseed() {
+    RNG.with(|rng| rng.seed(random_seed()))
+}
+
 /// Generates a random `bool`.
 #[inline]
 pub fn bool() -> bool {
@@ -87,6 +140,37 @@ pub fn shuffle<T>(slice: &mut [T]) {
     RNG.with(|rng| rng.shuffle(slice))
 }
 
+/// Chooses a random element from a slice.
+///
+/// Returns `None` if the slice is empty.
+#[inline]
+pub fn choose<T>(slice: &[T]) -> Option<&T> {
+    RNG.with(|rng| rng.choose(slice))
+}
+
+/// Chooses `n` distinct elements from a slice using reservoir sampling.
+///
+/// If `n` is greater than the length of the slice, every element is returned.
+#[inline]
+pub fn choose_multiple<T>(slice: &[T], n: usize) -> Vec<&T> {
+    RNG.with(|rng| rng.choose_multiple(slice, n))
+}
+
+/// Chooses an element from `items` with probability proportional to the weight returned by
+/// `weight_fn`.
+///
+/// Returns `None` if `items` is empty or every weight is zero.
+#[inline]
+pub fn weighted_choose<T>(items: &[T], weight_fn: impl Fn(&T) -> f64) -> Option<&T> {
+    RNG.with(|rng| rng.weighted_choose(items, weight_fn))
+}
+
+/// Fills `bytes` with random bytes.
+#[inline]
+pub fn fill(bytes: &mut [u8]) {
+    RNG.with(|rng| rng.fill(bytes))
+}
+
 macro_rules! integer {
     ($t:tt, $doc:tt) => {
         #[doc = $doc]
@@ -123,3 +207,320 @@ pub fn f32() -> f32 {
 pub fn f64() -> f64 {
     RNG.with(|rng| rng.f64())
 }
+
+/// Generates a random `f64` from a normal distribution with the given `mean` and `std_dev`.
+#[inline]
+pub fn f64_normal(mean: f64, std_dev: f64) -> f64 {
+    RNG.with(|rng| rng.f64_normal(mean, std_dev))
+}
+
+/// Generates a random `f64` from an exponential distribution with rate `lambda`.
+#[inline]
+pub fn f64_exponential(lambda: f64) -> f64 {
+    RNG.with(|rng| rng.f64_exponential(lambda))
+}
+
+/// A type that can be generated randomly by [`Rng::random`].
+pub trait RandomValue: Sized {
+    /// Generates a random value of this type using `rng`.
+    fn random(rng: &Rng) -> Self;
+}
+
+macro_rules! random_value_integer {
+    ($t:tt) => {
+        impl RandomValue for $t {
+            #[inline]
+            fn random(rng: &Rng) -> Self {
+                rng.$t(..)
+            }
+        }
+    };
+}
+
+random_value_integer!(u8);
+random_value_integer!(i8);
+random_value_integer!(u16);
+random_value_integer!(i16);
+random_value_integer!(u32);
+random_value_integer!(i32);
+random_value_integer!(u64);
+random_value_integer!(i64);
+random_value_integer!(u128);
+random_value_integer!(i128);
+random_value_integer!(usize);
+random_value_integer!(isize);
+
+impl RandomValue for bool {
+    #[inline]
+    fn random(rng: &Rng) -> Self {
+        rng.bool()
+    }
+}
+
+impl RandomValue for f32 {
+    #[inline]
+    fn random(rng: &Rng) -> Self {
+        rng.f32()
+    }
+}
+
+impl RandomValue for f64 {
+    #[inline]
+    fn random(rng: &Rng) -> Self {
+        rng.f64()
+    }
+}
+
+impl RandomValue for char {
+    #[inline]
+    fn random(rng: &Rng) -> Self {
+        loop {
+            if let Some(c) = char::from_u32(rng.u32(..=0x10FFFF)) {
+                return c;
+            }
+        }
+    }
+}
+
+macro_rules! random_value_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: RandomValue),+> RandomValue for ($($name,)+) {
+            #[inline]
+            fn random(rng: &Rng) -> Self {
+                ($($name::random(rng),)+)
+            }
+        }
+    };
+}
+
+random_value_tuple!(A);
+random_value_tuple!(A B);
+random_value_tuple!(A B C);
+random_value_tuple!(A B C D);
+random_value_tuple!(A B C D E);
+random_value_tuple!(A B C D E F);
+random_value_tuple!(A B C D E F G);
+random_value_tuple!(A B C D E F G H);
+random_value_tuple!(A B C D E F G H I);
+random_value_tuple!(A B C D E F G H I J);
+random_value_tuple!(A B C D E F G H I J K);
+random_value_tuple!(A B C D E F G H I J K L);
+
+// `[(); N].map(..)` (stable since 1.55) is used here instead of `std::array::from_fn`
+// (1.63) to keep this impl's MSRV as close as possible to the const generics (1.51) it
+// already requires.
+impl<T: RandomValue, const N: usize> RandomValue for [T; N] {
+    #[inline]
+    fn random(rng: &Rng) -> Self {
+        [(); N].map(|_| T::random(rng))
+    }
+}
+
+impl Rng {
+    /// Generates a random value of type `T`.
+    #[inline]
+    pub fn random<T: RandomValue>(&self) -> T {
+        T::random(self)
+    }
+}
+
+/// Generates a random value of type `T`.
+#[inline]
+pub fn random<T: RandomValue>() -> T {
+    RNG.with(|rng| rng.random())
+}
+
+impl Rng {
+    /// Chooses a random element from a slice.
+    ///
+    /// Returns `None` if the slice is empty.
+    pub fn choose<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(&slice[self.usize(..slice.len())])
+        }
+    }
+
+    /// Chooses `n` distinct elements from a slice using reservoir sampling.
+    ///
+    /// If `n` is greater than the length of the slice, every element is returned.
+    pub fn choose_multiple<'a, T>(&self, slice: &'a [T], n: usize) -> Vec<&'a T> {
+        let n = n.min(slice.len());
+        let mut reservoir: Vec<&'a T> = slice[..n].iter().collect();
+        for (i, item) in slice.iter().enumerate().skip(n) {
+            let j = self.usize(..=i);
+            if j < n {
+                reservoir[j] = item;
+            }
+        }
+        reservoir
+    }
+
+    /// Chooses an element from `items` with probability proportional to the weight returned by
+    /// `weight_fn`.
+    ///
+    /// Returns `None` if `items` is empty or every weight is zero.
+    pub fn weighted_choose<'a, T>(
+        &self,
+        items: &'a [T],
+        weight_fn: impl Fn(&T) -> f64,
+    ) -> Option<&'a T> {
+        let total: f64 = items.iter().map(&weight_fn).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut remaining = self.f64() * total;
+        for item in items {
+            remaining -= weight_fn(item);
+            if remaining < 0.0 {
+                return Some(item);
+            }
+        }
+        items.last()
+    }
+
+    /// Fills `bytes` with random bytes.
+    ///
+    /// This pulls full `u64` words from the generator and writes them out in little-endian
+    /// order, which is substantially faster than generating one byte at a time.
+    pub fn fill(&self, bytes: &mut [u8]) {
+        let mut chunks = bytes.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.u64(..).to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let word = self.u64(..).to_le_bytes();
+            remainder.copy_from_slice(&word[..remainder.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f64_normal_mean_is_centered_on_mean() {
+        let rng = Rng::with_seed(1);
+        let samples = 20_000;
+        let sum: f64 = (0..samples).map(|_| rng.f64_normal(10.0, 2.0)).sum();
+        let mean = sum / f64::from(samples);
+        assert!((mean - 10.0).abs() < 0.1, "mean was {mean}");
+    }
+
+    #[test]
+    fn f64_exponential_mean_is_one_over_lambda() {
+        let rng = Rng::with_seed(2);
+        let samples = 20_000;
+        let lambda = 4.0;
+        let sum: f64 = (0..samples).map(|_| rng.f64_exponential(lambda)).sum();
+        let mean = sum / f64::from(samples);
+        assert!((mean - 1.0 / lambda).abs() < 0.05, "mean was {mean}");
+    }
+
+    #[test]
+    fn rng_does_not_correlate_with_the_thread_local_stream() {
+        seed(42);
+        let handle = rng();
+        let handle_draws: Vec<u64> = (0..5).map(|_| handle.u64(..)).collect();
+        let tls_draws: Vec<u64> = (0..5).map(|_| u64(..)).collect();
+        assert_ne!(handle_draws, tls_draws);
+    }
+
+    #[test]
+    fn reseed_changes_the_thread_local_stream() {
+        seed(7);
+        let before: Vec<u64> = (0..5).map(|_| u64(..)).collect();
+        seed(7);
+        reseed();
+        let after: Vec<u64> = (0..5).map(|_| u64(..)).collect();
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn random_is_deterministic_for_a_fixed_seed() {
+        let a = Rng::with_seed(11);
+        let b = Rng::with_seed(11);
+        assert_eq!(a.random::<u32>(), b.random::<u32>());
+    }
+
+    #[test]
+    fn random_smoke_tests_tuples_arrays_and_char() {
+        let rng = Rng::with_seed(12);
+        let _: (u8, bool, f32) = rng.random();
+        let _: [u16; 4] = rng.random();
+        let c: char = rng.random();
+        let scalar = u32::from(c);
+        assert!(!(0xD800..=0xDFFF).contains(&scalar));
+    }
+
+    #[test]
+    fn choose_empty_slice_is_none() {
+        let rng = Rng::with_seed(3);
+        let empty: &[i32] = &[];
+        assert_eq!(rng.choose(empty), None);
+    }
+
+    #[test]
+    fn choose_multiple_returns_distinct_elements() {
+        let rng = Rng::with_seed(4);
+        let items: Vec<i32> = (0..50).collect();
+        let chosen = rng.choose_multiple(&items, 10);
+        assert_eq!(chosen.len(), 10);
+        let mut seen = std::collections::HashSet::new();
+        for &item in &chosen {
+            assert!(seen.insert(item), "duplicate element {item}");
+        }
+    }
+
+    #[test]
+    fn choose_multiple_caps_at_slice_len() {
+        let rng = Rng::with_seed(5);
+        let items = [1, 2, 3];
+        let chosen = rng.choose_multiple(&items, 10);
+        assert_eq!(chosen.len(), 3);
+    }
+
+    #[test]
+    fn weighted_choose_with_equal_weights_always_returns_some() {
+        let rng = Rng::with_seed(6);
+        let items = ["a", "b", "c"];
+        for _ in 0..100 {
+            assert!(rng.weighted_choose(&items, |_| 1.0).is_some());
+        }
+    }
+
+    #[test]
+    fn weighted_choose_all_zero_weights_is_none() {
+        let rng = Rng::with_seed(7);
+        let items = [1, 2, 3];
+        assert_eq!(rng.weighted_choose(&items, |_| 0.0), None);
+    }
+
+    #[test]
+    fn weighted_choose_empty_is_none() {
+        let rng = Rng::with_seed(8);
+        let empty: &[i32] = &[];
+        assert_eq!(rng.weighted_choose(empty, |_| 1.0), None);
+    }
+
+    #[test]
+    fn fill_handles_a_trailing_partial_chunk() {
+        let rng = Rng::with_seed(9);
+        let mut bytes = [0u8; 11];
+        rng.fill(&mut bytes);
+        assert!(bytes.iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn fill_is_not_limited_to_multiples_of_eight() {
+        for len in 0..17 {
+            let rng = Rng::with_seed(10);
+            let mut bytes = vec![0u8; len];
+            rng.fill(&mut bytes);
+            assert_eq!(bytes.len(), len);
+        }
+    }
+}